@@ -0,0 +1,8 @@
+use crate::bundle::FluentArgs;
+use crate::types::FluentValue;
+
+/// The signature every builtin Fluent function (`NUMBER()`, `DATETIME()`, ...) implements.
+pub type FluentFunction = for<'source> fn(&[FluentValue<'source>], &FluentArgs) -> FluentValue<'source>;
+
+/// Builtins registered under their Fluent-visible name by default.
+pub const DEFAULT_FUNCTIONS: &[(&str, FluentFunction)] = &[("DATETIME", crate::types::datetime::datetime)];
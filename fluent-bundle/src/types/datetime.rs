@@ -0,0 +1,454 @@
+use std::borrow::Cow;
+use std::default::Default;
+use std::str::FromStr;
+
+use crate::bundle::FluentArgs;
+use crate::types::FluentValue;
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FluentDateTimeStyle {
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl std::default::Default for FluentDateTimeStyle {
+    fn default() -> Self {
+        Self::Medium
+    }
+}
+
+impl From<&str> for FluentDateTimeStyle {
+    fn from(input: &str) -> Self {
+        match input {
+            "full" => Self::Full,
+            "long" => Self::Long,
+            "medium" => Self::Medium,
+            "short" => Self::Short,
+            _ => Self::default(),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum FluentDateTimeFieldStyle {
+    Numeric,
+    TwoDigit,
+}
+
+impl From<&str> for FluentDateTimeFieldStyle {
+    fn from(input: &str) -> Self {
+        match input {
+            "2-digit" => Self::TwoDigit,
+            _ => Self::Numeric,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub struct FluentDateTimeOptions {
+    pub date_style: Option<FluentDateTimeStyle>,
+    pub time_style: Option<FluentDateTimeStyle>,
+    pub year: Option<FluentDateTimeFieldStyle>,
+    pub month: Option<FluentDateTimeFieldStyle>,
+    pub day: Option<FluentDateTimeFieldStyle>,
+    pub hour: Option<FluentDateTimeFieldStyle>,
+    pub minute: Option<FluentDateTimeFieldStyle>,
+    pub second: Option<FluentDateTimeFieldStyle>,
+    pub hour12: Option<bool>,
+    pub time_zone: Option<String>,
+}
+
+impl FluentDateTimeOptions {
+    pub fn merge(&mut self, opts: &FluentArgs) {
+        for (key, value) in opts {
+            match (*key, value) {
+                ("dateStyle", FluentValue::String(n)) => {
+                    self.date_style = Some(n.as_ref().into());
+                }
+                ("timeStyle", FluentValue::String(n)) => {
+                    self.time_style = Some(n.as_ref().into());
+                }
+                ("year", FluentValue::String(n)) => {
+                    self.year = Some(n.as_ref().into());
+                }
+                ("month", FluentValue::String(n)) => {
+                    self.month = Some(n.as_ref().into());
+                }
+                ("day", FluentValue::String(n)) => {
+                    self.day = Some(n.as_ref().into());
+                }
+                ("hour", FluentValue::String(n)) => {
+                    self.hour = Some(n.as_ref().into());
+                }
+                ("minute", FluentValue::String(n)) => {
+                    self.minute = Some(n.as_ref().into());
+                }
+                ("second", FluentValue::String(n)) => {
+                    self.second = Some(n.as_ref().into());
+                }
+                ("hour12", FluentValue::String(n)) => {
+                    self.hour12 = Some(n.as_ref() == "true");
+                }
+                ("timeZone", FluentValue::String(n)) => {
+                    self.time_zone = Some(n.to_string());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// A naive (UTC-only) civil date/time, broken out of an epoch timestamp.
+/// `timeZone` is currently parsed and stored but not applied to the
+/// computed fields; see the `time_zone` field on [`FluentDateTimeOptions`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct CivilDateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl CivilDateTime {
+    /// Implements Howard Hinnant's `civil_from_days` algorithm to turn a
+    /// day count since the Unix epoch into a proleptic-Gregorian date.
+    fn from_epoch_seconds(epoch_seconds: i64) -> Self {
+        let days = epoch_seconds.div_euclid(86_400);
+        let time_of_day = epoch_seconds.rem_euclid(86_400);
+
+        let z = days + 719_468;
+        let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+        let doe = (z - era * 146_097) as u64;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let y = yoe as i64 + era * 400;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = if month <= 2 { y + 1 } else { y };
+
+        Self {
+            year,
+            month,
+            day,
+            hour: (time_of_day / 3_600) as u32,
+            minute: ((time_of_day % 3_600) / 60) as u32,
+            second: (time_of_day % 60) as u32,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FluentDateTime {
+    pub epoch_seconds: i64,
+    pub options: FluentDateTimeOptions,
+}
+
+impl FluentDateTime {
+    pub fn new(epoch_seconds: i64, options: FluentDateTimeOptions) -> Self {
+        Self {
+            epoch_seconds,
+            options,
+        }
+    }
+
+    pub fn as_string(&self) -> Cow<'static, str> {
+        let dt = CivilDateTime::from_epoch_seconds(self.epoch_seconds);
+        let opts = &self.options;
+
+        let explicit_date = opts.year.is_some() || opts.month.is_some() || opts.day.is_some();
+        let explicit_time = opts.hour.is_some() || opts.minute.is_some() || opts.second.is_some();
+        let want_date = explicit_date || opts.date_style.is_some();
+        let want_time = explicit_time || opts.time_style.is_some();
+        // with no fields requested at all, fall back to showing both
+        let (want_date, want_time) = if !want_date && !want_time {
+            (true, true)
+        } else {
+            (want_date, want_time)
+        };
+
+        let date_part = if explicit_date {
+            format_explicit_date(&dt, opts.year, opts.month, opts.day)
+        } else {
+            format_date_style(&dt, opts.date_style.unwrap_or_default())
+        };
+
+        let hour12 = opts.hour12.unwrap_or(false);
+        let time_part = if explicit_time {
+            format_explicit_time(&dt, opts.hour, opts.minute, opts.second, hour12)
+        } else {
+            format_time_style(&dt, opts.time_style.unwrap_or_default(), hour12)
+        };
+
+        match (want_date, want_time) {
+            (true, true) => format!("{} {}", date_part, time_part).into(),
+            (true, false) => date_part.into(),
+            (false, true) => time_part.into(),
+            (false, false) => unreachable!(),
+        }
+    }
+}
+
+/// Renders a field at `Numeric` (no leading zero) or `TwoDigit` precision.
+fn format_field(value: i64, style: FluentDateTimeFieldStyle) -> String {
+    match style {
+        FluentDateTimeFieldStyle::Numeric => format!("{}", value),
+        FluentDateTimeFieldStyle::TwoDigit => format!("{:02}", value.rem_euclid(100)),
+    }
+}
+
+/// Builds only the date fields the caller actually asked for, in `year-month-day` order.
+fn format_explicit_date(
+    dt: &CivilDateTime,
+    year: Option<FluentDateTimeFieldStyle>,
+    month: Option<FluentDateTimeFieldStyle>,
+    day: Option<FluentDateTimeFieldStyle>,
+) -> String {
+    let mut parts = Vec::new();
+    if let Some(style) = year {
+        parts.push(format_field(dt.year, style));
+    }
+    if let Some(style) = month {
+        parts.push(format_field(dt.month.into(), style));
+    }
+    if let Some(style) = day {
+        parts.push(format_field(dt.day.into(), style));
+    }
+    parts.join("-")
+}
+
+fn month_name(month: u32) -> &'static str {
+    match month {
+        1 => "January",
+        2 => "February",
+        3 => "March",
+        4 => "April",
+        5 => "May",
+        6 => "June",
+        7 => "July",
+        8 => "August",
+        9 => "September",
+        10 => "October",
+        11 => "November",
+        12 => "December",
+        _ => "",
+    }
+}
+
+fn format_date_style(dt: &CivilDateTime, style: FluentDateTimeStyle) -> String {
+    match style {
+        FluentDateTimeStyle::Short => format!("{}/{}/{:02}", dt.month, dt.day, dt.year.rem_euclid(100)),
+        FluentDateTimeStyle::Medium => format!("{:04}-{:02}-{:02}", dt.year, dt.month, dt.day),
+        FluentDateTimeStyle::Long | FluentDateTimeStyle::Full => {
+            format!("{} {}, {}", month_name(dt.month), dt.day, dt.year)
+        }
+    }
+}
+
+/// `hour12` rewrites `hour` to 12-hour form and reports the AM/PM suffix to append.
+fn display_hour(hour: u32, hour12: bool) -> (u32, Option<&'static str>) {
+    if !hour12 {
+        return (hour, None);
+    }
+    match hour {
+        0 => (12, Some("AM")),
+        1..=11 => (hour, Some("AM")),
+        12 => (12, Some("PM")),
+        _ => (hour - 12, Some("PM")),
+    }
+}
+
+/// Builds only the time fields the caller actually asked for, in `hour:minute:second` order.
+fn format_explicit_time(
+    dt: &CivilDateTime,
+    hour: Option<FluentDateTimeFieldStyle>,
+    minute: Option<FluentDateTimeFieldStyle>,
+    second: Option<FluentDateTimeFieldStyle>,
+    hour12: bool,
+) -> String {
+    let mut parts = Vec::new();
+    let mut suffix = None;
+    if let Some(style) = hour {
+        let (h, s) = display_hour(dt.hour, hour12);
+        suffix = s;
+        parts.push(format_field(h.into(), style));
+    }
+    if let Some(style) = minute {
+        parts.push(format_field(dt.minute.into(), style));
+    }
+    if let Some(style) = second {
+        parts.push(format_field(dt.second.into(), style));
+    }
+    let joined = parts.join(":");
+    match suffix {
+        Some(s) => format!("{} {}", joined, s),
+        None => joined,
+    }
+}
+
+fn format_time_style(dt: &CivilDateTime, style: FluentDateTimeStyle, hour12: bool) -> String {
+    let (h, suffix) = display_hour(dt.hour, hour12);
+    let base = match style {
+        FluentDateTimeStyle::Short => format!("{:02}:{:02}", h, dt.minute),
+        _ => format!("{:02}:{:02}:{:02}", h, dt.minute, dt.second),
+    };
+    match suffix {
+        Some(s) => format!("{} {}", base, s),
+        None => base,
+    }
+}
+
+impl FromStr for FluentDateTime {
+    type Err = std::num::ParseIntError;
+
+    /// Accepts an epoch-seconds integer, or a `YYYY-MM-DDTHH:MM:SS` /
+    /// `YYYY-MM-DD` ISO-8601 timestamp (always interpreted as UTC).
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if let Ok(epoch_seconds) = input.parse::<i64>() {
+            return Ok(FluentDateTime::new(
+                epoch_seconds,
+                FluentDateTimeOptions::default(),
+            ));
+        }
+
+        let (date, time) = match input.split_once('T') {
+            Some((date, time)) => (date, time.trim_end_matches('Z')),
+            None => (input, "00:00:00"),
+        };
+        let mut date_parts = date.splitn(3, '-');
+        let year: i64 = date_parts.next().unwrap_or_default().parse()?;
+        let month: u32 = date_parts.next().unwrap_or("1").parse()?;
+        let day: u32 = date_parts.next().unwrap_or("1").parse()?;
+
+        let mut time_parts = time.splitn(3, ':');
+        let hour: u32 = time_parts.next().unwrap_or("0").parse()?;
+        let minute: u32 = time_parts.next().unwrap_or("0").parse()?;
+        let second: u32 = time_parts.next().unwrap_or("0").parse()?;
+
+        // days_from_civil: the inverse of `CivilDateTime::from_epoch_seconds`.
+        let y = if month <= 2 { year - 1 } else { year };
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = (y - era * 400) as u64;
+        let mp = if month > 2 { month - 3 } else { month + 9 } as u64;
+        let doy = (153 * mp + 2) / 5 + day as u64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146_097 + doe as i64 - 719_468;
+
+        let epoch_seconds = days * 86_400 + i64::from(hour) * 3_600 + i64::from(minute) * 60 + i64::from(second);
+        Ok(FluentDateTime::new(
+            epoch_seconds,
+            FluentDateTimeOptions::default(),
+        ))
+    }
+}
+
+impl<'l> From<FluentDateTime> for FluentValue<'l> {
+    fn from(input: FluentDateTime) -> Self {
+        FluentValue::DateTime(input)
+    }
+}
+
+/// Implements the `DATETIME()` builtin, mirroring how `NUMBER()` wraps
+/// [`crate::types::number::FluentNumber`]: the first positional argument is
+/// coerced to a `FluentDateTime`, and any named arguments are merged into
+/// its formatting options.
+pub fn datetime<'source>(
+    positional: &[FluentValue<'source>],
+    named: &FluentArgs,
+) -> FluentValue<'source> {
+    let mut dt = match positional.first() {
+        Some(FluentValue::DateTime(dt)) => dt.clone(),
+        Some(FluentValue::String(s)) => match s.parse::<FluentDateTime>() {
+            Ok(dt) => dt,
+            Err(_) => return FluentValue::Error,
+        },
+        Some(FluentValue::Number(n)) => {
+            FluentDateTime::new(n.value as i64, FluentDateTimeOptions::default())
+        }
+        _ => return FluentValue::Error,
+    };
+    dt.options.merge(named);
+    dt.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epoch_round_trips_through_iso() {
+        let dt = "2021-03-14T01:02:03Z".parse::<FluentDateTime>().unwrap();
+        assert_eq!(dt.epoch_seconds, 1_615_683_723);
+    }
+
+    #[test]
+    fn as_string_shows_date_and_time_by_default() {
+        let dt = FluentDateTime::new(1_615_683_723, FluentDateTimeOptions::default());
+        assert_eq!(dt.as_string(), "2021-03-14 01:02:03");
+    }
+
+    #[test]
+    fn as_string_honors_hour12() {
+        let dt = FluentDateTime::new(
+            1_615_683_723,
+            FluentDateTimeOptions {
+                hour12: Some(true),
+                ..Default::default()
+            },
+        );
+        assert_eq!(dt.as_string(), "2021-03-14 01:02:03 AM");
+    }
+
+    #[test]
+    fn as_string_can_show_date_only() {
+        let dt = FluentDateTime::new(
+            1_615_683_723,
+            FluentDateTimeOptions {
+                date_style: Some(FluentDateTimeStyle::Short),
+                ..Default::default()
+            },
+        );
+        assert_eq!(dt.as_string(), "3/14/21");
+    }
+
+    #[test]
+    fn as_string_date_style_full_differs_from_short() {
+        let dt = FluentDateTime::new(
+            1_615_683_723,
+            FluentDateTimeOptions {
+                date_style: Some(FluentDateTimeStyle::Full),
+                ..Default::default()
+            },
+        );
+        assert_eq!(dt.as_string(), "March 14, 2021");
+    }
+
+    #[test]
+    fn as_string_honors_individual_date_fields() {
+        let dt = FluentDateTime::new(
+            1_615_683_723,
+            FluentDateTimeOptions {
+                year: Some(FluentDateTimeFieldStyle::Numeric),
+                ..Default::default()
+            },
+        );
+        assert_eq!(dt.as_string(), "2021");
+    }
+
+    #[test]
+    fn as_string_honors_individual_time_fields() {
+        let dt = FluentDateTime::new(
+            1_615_683_723,
+            FluentDateTimeOptions {
+                hour: Some(FluentDateTimeFieldStyle::TwoDigit),
+                minute: Some(FluentDateTimeFieldStyle::TwoDigit),
+                ..Default::default()
+            },
+        );
+        assert_eq!(dt.as_string(), "01:02");
+    }
+}
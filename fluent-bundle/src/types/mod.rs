@@ -0,0 +1,25 @@
+use std::borrow::Cow;
+
+pub mod datetime;
+pub mod number;
+
+pub use datetime::FluentDateTime;
+pub use number::FluentNumber;
+
+/// A resolved runtime value: anything a pattern can interpolate into a
+/// message, or that a function like `NUMBER()`/`DATETIME()` can return.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FluentValue<'source> {
+    String(Cow<'source, str>),
+    Number(FluentNumber),
+    DateTime(FluentDateTime),
+    /// A recoverable resolution failure (e.g. a function given arguments it
+    /// can't coerce), matching Fluent's "errors don't abort the bundle" model.
+    Error,
+}
+
+impl<'source> FluentValue<'source> {
+    pub fn try_number<N: Into<FluentNumber>>(n: N) -> Self {
+        FluentValue::Number(n.into())
+    }
+}
@@ -123,39 +123,385 @@ impl FluentNumberOptions {
 pub struct FluentNumber {
     pub value: f64,
     pub options: FluentNumberOptions,
+    /// The original value as an exact, sign-prefixed decimal string, when
+    /// available, to avoid `f64` precision loss.
+    pub exact: Option<String>,
 }
 
 impl FluentNumber {
     pub fn new(value: f64, options: FluentNumberOptions) -> Self {
-        Self { value, options }
+        Self {
+            value,
+            options,
+            exact: None,
+        }
+    }
+
+    /// As [`FluentNumber::new`], but also records the exact decimal text
+    /// `value` was derived from.
+    pub fn new_exact(value: f64, exact: String, options: FluentNumberOptions) -> Self {
+        Self {
+            value,
+            options,
+            exact: Some(exact),
+        }
     }
 
     pub fn as_string(&self) -> Cow<'static, str> {
+        let mut val = self.formatted_digits();
+
+        if self.options.use_grouping {
+            val = group_digits(&val);
+        }
+
+        match self.options.style {
+            FluentNumberStyle::Percent => format!("{}%", val).into(),
+            FluentNumberStyle::Currency => {
+                let code = self.options.currency.as_deref().unwrap_or("");
+                let (sign, val) = match val.strip_prefix('-') {
+                    Some(rest) => ("-", rest),
+                    None => ("", val.as_str()),
+                };
+                match self.options.currency_display {
+                    FluentNumberCurrencyDisplayStyle::Code => {
+                        format!("{}{} {}", sign, val, code).into()
+                    }
+                    FluentNumberCurrencyDisplayStyle::Name => {
+                        format!("{}{} {}", sign, val, currency_name(code)).into()
+                    }
+                    FluentNumberCurrencyDisplayStyle::Symbol => {
+                        format!("{}{}{}", sign, currency_symbol(code), val).into()
+                    }
+                }
+            }
+            FluentNumberStyle::Decimal => val.into(),
+        }
+    }
+
+    /// Renders the number's digits, with rounding, significant-digits, and
+    /// fraction-digit options applied, but before grouping separators or
+    /// style affixes (`%`, currency symbols) are added.
+    fn formatted_digits(&self) -> String {
+        if let Some(exact) = self.exact_formatted_digits() {
+            return exact;
+        }
+
+        // `Percent` scales the underlying value before any digit formatting happens.
+        let value = match self.options.style {
+            FluentNumberStyle::Percent => self.value * 100.0,
+            _ => self.value,
+        };
+
+        // When significant-digit options are present they take precedence over
+        // minimum/maximum fraction digits entirely, matching how Intl.NumberFormat
+        // resolves the conflict.
+        if self.options.minimum_significant_digits.is_some()
+            || self.options.maximum_significant_digits.is_some()
+        {
+            return Self::formatted_significant_digits(
+                value,
+                self.options.minimum_significant_digits.unwrap_or(1).max(1),
+                self.options.maximum_significant_digits,
+            );
+        }
+
         let mut max_frac_digits = self.options.maximum_fraction_digits.unwrap_or(15);
         // since the plural code currently parses the resulting fractional digits into a usize, we can't
         // have a precision above 9 digits on 32 bit platforms
         if std::mem::size_of::<usize>() < 8 {
             max_frac_digits = max_frac_digits.min(9);
         }
-        // create the string with maximum precision
         let with_max_precision = format!(
             "{number:.precision$}",
-            number = self.value,
+            number = value,
             precision = max_frac_digits
         );
-        // and then remove any excess trailing zeros
-        let mut val: Cow<str> = with_max_precision.trim_end_matches('0').into();
-        // adding back any required to meet minimum_fraction_digits
-        if let Some(minfd) = self.options.minimum_fraction_digits {
-            let pos = val.find('.').expect("expected . in formatted string");
-            let frac_num = val.len() - pos - 1;
-            let zeros_needed = minfd - frac_num;
-            if zeros_needed > 0 {
-                val = format!("{}{}", val, "0".repeat(zeros_needed)).into();
+        pad_min_fraction_digits(
+            trim_trailing_fraction_zeros(&with_max_precision),
+            self.options.minimum_fraction_digits,
+        )
+    }
+
+    /// Rounds `value` to between `min_sig` and `max_sig` significant digits.
+    /// Formats `value` directly at the resulting fraction-digit precision
+    /// (rather than pre-rounding it with float multiplication, which would
+    /// reintroduce the representable-value error `format!`'s correctly-rounded
+    /// conversion otherwise avoids) and only falls back to `round_to_precision`
+    /// when that precision would be negative, which `format!` can't express.
+    fn formatted_significant_digits(value: f64, min_sig: usize, max_sig: Option<usize>) -> String {
+        let max_sig = max_sig.unwrap_or(21).max(min_sig);
+        let exponent = if value == 0.0 {
+            0
+        } else {
+            value.abs().log10().floor() as i32
+        };
+        let precision = max_sig as i32 - 1 - exponent;
+        let with_max_precision = if precision >= 0 {
+            format!("{number:.precision$}", number = value, precision = precision as usize)
+        } else {
+            format!("{}", round_to_precision(value, precision))
+        };
+
+        // rounding can carry into a higher power of ten (e.g. 999.5 -> 1000 at 3
+        // significant digits), which shifts the exponent and thus how many
+        // fraction digits are needed to hit the minimum.
+        let rounded: f64 = with_max_precision.parse().unwrap_or(value);
+        let exponent = if rounded == 0.0 {
+            0
+        } else {
+            rounded.abs().log10().floor() as i32
+        };
+        let min_frac = (min_sig as i32 - 1 - exponent).max(0) as usize;
+        pad_min_fraction_digits(trim_trailing_fraction_zeros(&with_max_precision), Some(min_frac))
+    }
+
+    /// As `formatted_digits`, but operating on `self.exact`'s text directly
+    /// so large integers and long decimal fractions never round-trip
+    /// through `f64`. Falls back to `None` when significant-digit rounding
+    /// is requested, since that needs the value's order of magnitude.
+    fn exact_formatted_digits(&self) -> Option<String> {
+        if self.options.minimum_significant_digits.is_some()
+            || self.options.maximum_significant_digits.is_some()
+        {
+            return None;
+        }
+        let exact = self.exact.as_ref()?;
+        let scaled = match self.options.style {
+            FluentNumberStyle::Percent => shift_decimal_point(exact, 2),
+            _ => strip_leading_zeros(exact),
+        };
+
+        // an unset `maximum_fraction_digits` preserves every digit `exact` has,
+        // rather than defaulting to 15 like the `f64` path does; on 32-bit
+        // targets both paths still cap it at 9 fraction digits.
+        let natural_frac_digits = scaled.find('.').map_or(0, |pos| scaled.len() - pos - 1);
+        let mut max_frac_digits = self
+            .options
+            .maximum_fraction_digits
+            .unwrap_or(natural_frac_digits);
+        if std::mem::size_of::<usize>() < 8 {
+            max_frac_digits = max_frac_digits.min(9);
+        }
+        let rounded = round_decimal_string(&scaled, max_frac_digits);
+        Some(pad_min_fraction_digits(
+            trim_trailing_fraction_zeros(&rounded),
+            self.options.minimum_fraction_digits,
+        ))
+    }
+}
+
+/// Removes excess trailing zeros from the fractional part of a formatted
+/// number string, leaving the integer part (which has no decimal point)
+/// untouched.
+fn trim_trailing_fraction_zeros(input: &str) -> String {
+    if input.contains('.') {
+        input.trim_end_matches('0').to_string()
+    } else {
+        input.to_string()
+    }
+}
+
+/// Pads the fractional part of `val` with trailing zeros so it has at least
+/// `min_frac_digits` digits, then lops off a now-trailing `.` if no
+/// fractional part ended up being needed at all.
+fn pad_min_fraction_digits(mut val: String, min_frac_digits: Option<usize>) -> String {
+    if let Some(minfd) = min_frac_digits {
+        let frac_num = val.find('.').map_or(0, |pos| val.len() - pos - 1);
+        let zeros_needed = minfd.saturating_sub(frac_num);
+        if zeros_needed > 0 {
+            if !val.contains('.') {
+                val.push('.');
             }
+            val.push_str(&"0".repeat(zeros_needed));
         }
-        // lop off any trailing '.', then return an owned value
-        val.trim_end_matches('.').to_string().into()
+    }
+    val.trim_end_matches('.').to_string()
+}
+
+/// Rounds a sign-prefixed decimal string to `frac_digits` fractional digits,
+/// half-up, entirely in text so precision beyond `f64`'s mantissa survives.
+fn round_decimal_string(input: &str, frac_digits: usize) -> String {
+    let (sign, rest) = match input.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", input),
+    };
+    let (int_part, frac_part) = match rest.find('.') {
+        Some(pos) => (&rest[..pos], &rest[pos + 1..]),
+        None => (rest, ""),
+    };
+
+    if frac_part.len() <= frac_digits {
+        return input.to_string();
+    }
+
+    let round_up = frac_part.as_bytes()[frac_digits] >= b'5';
+    let mut digits: Vec<u8> = int_part.bytes().chain(frac_part[..frac_digits].bytes()).collect();
+    if round_up {
+        let mut i = digits.len();
+        loop {
+            if i == 0 {
+                digits.insert(0, b'1');
+                break;
+            }
+            i -= 1;
+            if digits[i] == b'9' {
+                digits[i] = b'0';
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+    }
+
+    let split_at = digits.len() - frac_digits;
+    let int_str = String::from_utf8(digits[..split_at].to_vec()).expect("ASCII digits");
+    let frac_str = String::from_utf8(digits[split_at..].to_vec()).expect("ASCII digits");
+    if frac_str.is_empty() {
+        format!("{}{}", sign, int_str)
+    } else {
+        format!("{}{}.{}", sign, int_str, frac_str)
+    }
+}
+
+/// Strips leading zeros from the integer part of a sign-prefixed decimal
+/// string, leaving a single `0` if the integer part was all zeros.
+fn strip_leading_zeros(input: &str) -> String {
+    let (sign, rest) = match input.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", input),
+    };
+    let (int_part, frac_part) = match rest.find('.') {
+        Some(pos) => (&rest[..pos], &rest[pos..]),
+        None => (rest, ""),
+    };
+    let trimmed = int_part.trim_start_matches('0');
+    let int_str = if trimmed.is_empty() { "0" } else { trimmed };
+    format!("{}{}{}", sign, int_str, frac_part)
+}
+
+/// Shifts the decimal point in a sign-prefixed decimal string right by
+/// `shift` places, used to apply `Percent` scaling (×100) without lossy
+/// floating-point multiplication.
+fn shift_decimal_point(input: &str, shift: i32) -> String {
+    let (sign, rest) = match input.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", input),
+    };
+    let (int_part, frac_part) = match rest.find('.') {
+        Some(pos) => (&rest[..pos], &rest[pos + 1..]),
+        None => (rest, ""),
+    };
+
+    let mut digits: Vec<u8> = int_part.bytes().chain(frac_part.bytes()).collect();
+    let mut point = int_part.len() as i32 + shift;
+
+    if point < 0 {
+        let pad = (-point) as usize;
+        let mut padded = vec![b'0'; pad];
+        padded.extend(&digits);
+        digits = padded;
+        point = 0;
+    }
+    if point as usize > digits.len() {
+        digits.extend(std::iter::repeat_n(b'0', point as usize - digits.len()));
+    }
+
+    let (int_digits, frac_digits) = digits.split_at(point as usize);
+    let mut int_str = String::from_utf8(int_digits.to_vec())
+        .expect("ASCII digits")
+        .trim_start_matches('0')
+        .to_string();
+    if int_str.is_empty() {
+        int_str.push('0');
+    }
+    let frac_str = String::from_utf8(frac_digits.to_vec()).expect("ASCII digits");
+    if frac_str.is_empty() {
+        format!("{}{}", sign, int_str)
+    } else {
+        format!("{}{}.{}", sign, int_str, frac_str)
+    }
+}
+
+/// Whether `s` is a bare `-`-optional decimal string (digits, at most one
+/// `.`, no exponent, no leading `+`) — the form every `exact`-handling
+/// helper in this module (`round_decimal_string`, `shift_decimal_point`)
+/// assumes.
+fn is_plain_decimal(s: &str) -> bool {
+    let rest = s.strip_prefix('-').unwrap_or(s);
+    if rest.is_empty() {
+        return false;
+    }
+    let mut seen_dot = false;
+    let mut seen_digit = false;
+    for b in rest.bytes() {
+        match b {
+            b'0'..=b'9' => seen_digit = true,
+            b'.' if !seen_dot => seen_dot = true,
+            _ => return false,
+        }
+    }
+    seen_digit
+}
+
+/// Rounds `x` to `precision` decimal places; `precision` may be negative to
+/// round to a power of ten above the decimal point (e.g. `-2` rounds to the
+/// nearest 100).
+fn round_to_precision(x: f64, precision: i32) -> f64 {
+    let factor = 10f64.powi(precision);
+    (x * factor).round() / factor
+}
+
+/// Inserts `,` every three digits into the integer part of a formatted
+/// number, leaving any fractional part (and its leading `.`) untouched.
+/// A leading `-` sign is preserved ahead of the grouped digits.
+fn group_digits(input: &str) -> String {
+    let (sign, rest) = match input.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", input),
+    };
+    let (int_part, frac_part) = match rest.find('.') {
+        Some(pos) => (&rest[..pos], &rest[pos..]),
+        None => (rest, ""),
+    };
+
+    let mut grouped = String::with_capacity(int_part.len() + int_part.len() / 3);
+    for (i, ch) in int_part.chars().rev().enumerate() {
+        if i > 0 && i % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    let grouped: String = grouped.chars().rev().collect();
+
+    format!("{}{}{}", sign, grouped, frac_part)
+}
+
+/// A small table of well-known currency affixes. Unrecognized codes fall
+/// back to the ISO code itself, matching the behavior of `currencyDisplay:
+/// "code"`.
+fn currency_symbol(code: &str) -> &str {
+    match code {
+        "USD" => "$",
+        "CAD" => "CA$",
+        "AUD" => "AU$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" => "¥",
+        "CNY" => "CN¥",
+        _ => code,
+    }
+}
+
+fn currency_name(code: &str) -> &str {
+    match code {
+        "USD" => "US dollars",
+        "CAD" => "Canadian dollars",
+        "AUD" => "Australian dollars",
+        "EUR" => "euros",
+        "GBP" => "British pounds",
+        "JPY" => "Japanese yen",
+        "CNY" => "Chinese yuan",
+        _ => code,
     }
 }
 
@@ -169,7 +515,15 @@ impl FromStr for FluentNumber {
                 minimum_fraction_digits: mfd,
                 ..Default::default()
             };
-            FluentNumber::new(n, opts)
+            // `input` already parsed successfully as an `f64` above, but may be
+            // in a form `exact`'s plain-decimal string ops can't handle (exponent
+            // notation, `+`, `inf`, `nan`); only keep it as the exact backing
+            // when it's a plain decimal.
+            if is_plain_decimal(input) {
+                FluentNumber::new_exact(n, input.to_string(), opts)
+            } else {
+                FluentNumber::new(n, opts)
+            }
         })
     }
 }
@@ -180,71 +534,129 @@ impl<'l> From<FluentNumber> for FluentValue<'l> {
     }
 }
 
-macro_rules! from_num {
-    ($num:ty) => {
-        impl From<$num> for FluentNumber {
-            fn from(n: $num) -> Self {
-                FluentNumber {
-                    value: n as f64,
-                    options: FluentNumberOptions::default(),
+/// Any scalar type that can be carried into a [`FluentNumber`]. This crate
+/// implements it for the primitive integer and float types (see the impls
+/// below); a caller's own numeric type becomes a valid `FluentValue` argument
+/// once it implements this trait. It is not implemented for `NonZero*`,
+/// `Wrapping<T>` or other wrapper types, and the orphan rule keeps a downstream
+/// crate from implementing it for types that crate does not define.
+pub trait FluentNumberValue {
+    /// A lossy `f64` view of the value, used when no exact backing is given.
+    fn to_fluent_f64(&self) -> f64;
+
+    /// An exact, sign-prefixed decimal string (no exponent notation), if one
+    /// can be produced without precision loss. Integer types should always
+    /// return `Some`; see [`FluentNumber::exact`].
+    fn to_fluent_exact(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<T: FluentNumberValue> From<T> for FluentNumber {
+    fn from(n: T) -> Self {
+        FluentNumber {
+            value: n.to_fluent_f64(),
+            options: FluentNumberOptions::default(),
+            exact: n.to_fluent_exact(),
+        }
+    }
+}
+
+/// References to a `FluentNumberValue` are themselves a `FluentNumberValue`
+/// (delegating through), so the blanket `From` impls for `FluentNumber` and
+/// `FluentValue` cover both
+/// `n.into()` and `(&n).into()` without a second, overlapping blanket impl.
+impl<T: FluentNumberValue> FluentNumberValue for &T {
+    fn to_fluent_f64(&self) -> f64 {
+        (**self).to_fluent_f64()
+    }
+    fn to_fluent_exact(&self) -> Option<String> {
+        (**self).to_fluent_exact()
+    }
+}
+
+impl<T: FluentNumberValue> From<T> for FluentValue<'_> {
+    fn from(n: T) -> Self {
+        FluentValue::Number(n.into())
+    }
+}
+
+macro_rules! impl_fluent_number_value_int {
+    ($($num:ty)+) => {
+        $(
+            impl FluentNumberValue for $num {
+                fn to_fluent_f64(&self) -> f64 {
+                    *self as f64
+                }
+                fn to_fluent_exact(&self) -> Option<String> {
+                    Some(self.to_string())
                 }
             }
-        }
-        impl From<&$num> for FluentNumber {
-            fn from(n: &$num) -> Self {
-                FluentNumber {
-                    value: *n as f64,
-                    options: FluentNumberOptions::default(),
+        )+
+    };
+}
+
+macro_rules! impl_fluent_number_value_float {
+    ($($num:ty)+) => {
+        $(
+            impl FluentNumberValue for $num {
+                fn to_fluent_f64(&self) -> f64 {
+                    *self as f64
                 }
             }
-        }
+        )+
+    };
+}
+
+/// Generates the (non-generic) reverse conversions — `FluentNumber` back out
+/// to a primitive — for a concrete numeric type. These can't be expressed as
+/// a single blanket impl the way ingestion can: going from a `FluentNumber`'s
+/// `f64` to an arbitrary caller-supplied `T` needs a per-`T` cast. Prefers
+/// parsing `exact` directly so integers beyond `f64`'s 53-bit mantissa
+/// round-trip exactly; falls back to casting `value` when there's no exact
+/// backing, or it doesn't fit `$num`.
+macro_rules! from_fluent_number {
+    ($num:ty) => {
         impl From<FluentNumber> for $num {
             fn from(input: FluentNumber) -> Self {
-                input.value as $num
+                (&input).into()
             }
         }
         impl From<&FluentNumber> for $num {
             fn from(input: &FluentNumber) -> Self {
-                input.value as $num
-            }
-        }
-        impl From<$num> for FluentValue<'_> {
-            fn from(n: $num) -> Self {
-                FluentValue::Number(n.into())
-            }
-        }
-        impl From<&$num> for FluentValue<'_> {
-            fn from(n: &$num) -> Self {
-                FluentValue::Number(n.into())
+                input
+                    .exact
+                    .as_deref()
+                    .and_then(|exact| exact.parse::<$num>().ok())
+                    .unwrap_or(input.value as $num)
             }
         }
     };
     ($($num:ty)+) => {
-        $(from_num!($num);)+
+        $(from_fluent_number!($num);)+
     };
 }
 
 impl From<&FluentNumber> for PluralOperands {
     fn from(input: &FluentNumber) -> Self {
-        let mut operands: PluralOperands = input
-            .as_string()
-            .as_ref()
+        // formatted_digits(), not as_string(): operands need the rounded/padded
+        // digits but not grouping commas or style affixes. `c`/`e` aren't set;
+        // `intl_pluralrules`'s `PluralOperands` doesn't expose them.
+        input
+            .formatted_digits()
+            .as_str()
             .try_into()
-            .expect("Failed to generate operands out of FluentNumber");
-        if let Some(mfd) = input.options.minimum_fraction_digits {
-            if mfd > operands.v {
-                operands.f *= 10_usize.pow(mfd as u32 - operands.v as u32);
-                operands.v = mfd;
-            }
-        }
-        // XXX: Add support for other options.
-        operands
+            .expect("Failed to generate operands out of FluentNumber")
     }
 }
 
-from_num!(i8 i16 i32 i64 i128 isize);
-from_num!(u8 u16 u32 u64 u128 usize);
-from_num!(f32 f64);
+impl_fluent_number_value_int!(i8 i16 i32 i64 i128 isize);
+impl_fluent_number_value_int!(u8 u16 u32 u64 u128 usize);
+impl_fluent_number_value_float!(f32 f64);
+
+from_fluent_number!(i8 i16 i32 i64 i128 isize);
+from_fluent_number!(u8 u16 u32 u64 u128 usize);
+from_fluent_number!(f32 f64);
 
 #[cfg(test)]
 mod tests {
@@ -257,4 +669,261 @@ mod tests {
         let z: FluentValue = y.into();
         assert_eq!(z, FluentValue::try_number(1));
     }
+
+    #[test]
+    fn percent_style() {
+        let n = FluentNumber::new(
+            0.42,
+            FluentNumberOptions {
+                style: FluentNumberStyle::Percent,
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "42%");
+    }
+
+    #[test]
+    fn currency_symbol_style() {
+        let n = FluentNumber::new(
+            1234.5,
+            FluentNumberOptions {
+                style: FluentNumberStyle::Currency,
+                currency: Some("USD".to_string()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "$1,234.5");
+    }
+
+    #[test]
+    fn currency_symbol_style_negative() {
+        let n = FluentNumber::new(
+            -5.0,
+            FluentNumberOptions {
+                style: FluentNumberStyle::Currency,
+                currency: Some("USD".to_string()),
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "-$5");
+    }
+
+    #[test]
+    fn currency_code_style() {
+        let n = FluentNumber::new(
+            10.0,
+            FluentNumberOptions {
+                style: FluentNumberStyle::Currency,
+                currency: Some("EUR".to_string()),
+                currency_display: FluentNumberCurrencyDisplayStyle::Code,
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "10 EUR");
+    }
+
+    #[test]
+    fn grouping() {
+        let n = FluentNumber::new(1234567.0, FluentNumberOptions::default());
+        assert_eq!(n.as_string(), "1,234,567");
+    }
+
+    #[test]
+    fn significant_digits_rounds_down() {
+        let n = FluentNumber::new(
+            123.456,
+            FluentNumberOptions {
+                maximum_significant_digits: Some(3),
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "123");
+    }
+
+    #[test]
+    fn significant_digits_rounds_up_carry() {
+        let n = FluentNumber::new(
+            999.5,
+            FluentNumberOptions {
+                maximum_significant_digits: Some(3),
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "1,000");
+    }
+
+    #[test]
+    fn significant_digits_pads_minimum() {
+        let n = FluentNumber::new(
+            1.0,
+            FluentNumberOptions {
+                minimum_significant_digits: Some(3),
+                maximum_significant_digits: Some(5),
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "1.00");
+    }
+
+    #[test]
+    fn significant_digits_zero() {
+        let n = FluentNumber::new(
+            0.0,
+            FluentNumberOptions {
+                minimum_significant_digits: Some(3),
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "0.00");
+    }
+
+    #[test]
+    fn significant_digits_override_fraction_digits() {
+        let n = FluentNumber::new(
+            5.67891,
+            FluentNumberOptions {
+                minimum_fraction_digits: Some(0),
+                maximum_fraction_digits: Some(0),
+                maximum_significant_digits: Some(4),
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "5.679");
+    }
+
+    #[test]
+    fn plural_operands_reflect_padded_fraction_digits() {
+        let n = FluentNumber::new(
+            1.5,
+            FluentNumberOptions {
+                minimum_fraction_digits: Some(3),
+                ..Default::default()
+            },
+        );
+        let operands: PluralOperands = (&n).into();
+        assert_eq!(operands.v, 3);
+        assert_eq!(operands.f, 500);
+        assert_eq!(operands.t, 5);
+    }
+
+    #[test]
+    fn plural_operands_ignore_grouping_and_style() {
+        let n = FluentNumber::new(
+            1234.5,
+            FluentNumberOptions {
+                style: FluentNumberStyle::Currency,
+                currency: Some("USD".to_string()),
+                ..Default::default()
+            },
+        );
+        let operands: PluralOperands = (&n).into();
+        assert_eq!(operands.i, 1234);
+        assert_eq!(operands.v, 1);
+        assert_eq!(operands.f, 5);
+    }
+
+    #[test]
+    fn plural_operands_reflect_significant_digit_rounding() {
+        let n = FluentNumber::new(
+            999.5,
+            FluentNumberOptions {
+                maximum_significant_digits: Some(3),
+                ..Default::default()
+            },
+        );
+        let operands: PluralOperands = (&n).into();
+        assert_eq!(operands.i, 1000);
+        assert_eq!(operands.v, 0);
+    }
+
+    #[test]
+    fn exact_preserves_large_integers_beyond_f64_precision() {
+        // 2^63 - 1: well beyond f64's 53-bit mantissa.
+        let n: FluentNumber = 9_223_372_036_854_775_807i64.into();
+        assert_eq!(n.as_string(), "9,223,372,036,854,775,807");
+    }
+
+    #[test]
+    fn exact_round_trips_back_out_beyond_f64_precision() {
+        // 2^53 + 1: the smallest integer an f64 can't represent exactly.
+        let original = 9_007_199_254_740_993i64;
+        let n: FluentNumber = original.into();
+        let back: i64 = n.into();
+        assert_eq!(back, original);
+    }
+
+    #[test]
+    fn exact_strips_leading_zeros() {
+        let n: FluentNumber = "007".parse().unwrap();
+        assert_eq!(n.as_string(), "7");
+    }
+
+    #[test]
+    fn exact_preserves_long_decimal_fractions() {
+        let n: FluentNumber = "1.123456789012345678".parse().unwrap();
+        assert_eq!(n.as_string(), "1.123456789012345678");
+    }
+
+    #[test]
+    fn exact_backed_percent_has_no_rounding_error() {
+        let n = FluentNumber::new_exact(
+            0.0001,
+            "0.0001".to_string(),
+            FluentNumberOptions {
+                style: FluentNumberStyle::Percent,
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "0.01%");
+    }
+
+    #[test]
+    fn exact_is_dropped_for_significant_digits() {
+        // significant-digits rounding needs the order of magnitude, which the
+        // exact-decimal path doesn't compute; it must still produce a sane result
+        // by falling back to the f64-based path rather than ignoring the option.
+        let n = FluentNumber::new_exact(
+            123.456,
+            "123.456".to_string(),
+            FluentNumberOptions {
+                maximum_significant_digits: Some(3),
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "123");
+    }
+
+    /// A stand-in for a caller-defined numeric type this crate doesn't
+    /// enumerate: callers can make it a valid `FluentValue` argument just by
+    /// implementing `FluentNumberValue`, without any change here.
+    struct EvenNumber(i32);
+
+    impl FluentNumberValue for EvenNumber {
+        fn to_fluent_f64(&self) -> f64 {
+            self.0 as f64
+        }
+        fn to_fluent_exact(&self) -> Option<String> {
+            Some(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn custom_type_via_fluent_number_value() {
+        let n: FluentNumber = EvenNumber(42).into();
+        assert_eq!(n.as_string(), "42");
+        let v: FluentValue = (&EvenNumber(42)).into();
+        assert_eq!(v, FluentValue::try_number(42));
+    }
+
+    #[test]
+    fn grouping_disabled() {
+        let n = FluentNumber::new(
+            1234567.0,
+            FluentNumberOptions {
+                use_grouping: false,
+                ..Default::default()
+            },
+        );
+        assert_eq!(n.as_string(), "1234567");
+    }
 }